@@ -0,0 +1,92 @@
+use std::cmp::Ordering;
+
+use crate::aabb::Aabb;
+use crate::common;
+use crate::hittable::{HitRecord, Hittable};
+use crate::ray::Ray;
+
+pub enum BvhNode {
+    Leaf {
+        object: Box<dyn Hittable>,
+        b_box: Aabb,
+    },
+    Branch {
+        left: Box<BvhNode>,
+        right: Box<BvhNode>,
+        b_box: Aabb,
+    },
+}
+
+impl BvhNode {
+    pub fn new(mut objects: Vec<Box<dyn Hittable>>) -> BvhNode {
+        assert!(
+            !objects.is_empty(),
+            "BvhNode constructor requires at least one object"
+        );
+
+        let axis = common::random_int_range(0, 2) as usize;
+        objects.sort_by(|a, b| box_compare(a.as_ref(), b.as_ref(), axis));
+
+        if objects.len() == 1 {
+            let object = objects.pop().unwrap();
+            let b_box = object
+                .bounding_box()
+                .expect("No bounding box in BvhNode constructor");
+            BvhNode::Leaf { object, b_box }
+        } else {
+            let right_objects = objects.split_off(objects.len() / 2);
+            let left = BvhNode::new(objects);
+            let right = BvhNode::new(right_objects);
+            let b_box = Aabb::surrounding_box(left.b_box(), right.b_box());
+
+            BvhNode::Branch {
+                left: Box::new(left),
+                right: Box::new(right),
+                b_box,
+            }
+        }
+    }
+
+    fn b_box(&self) -> Aabb {
+        match self {
+            BvhNode::Leaf { b_box, .. } => *b_box,
+            BvhNode::Branch { b_box, .. } => *b_box,
+        }
+    }
+}
+
+fn box_compare(a: &dyn Hittable, b: &dyn Hittable, axis: usize) -> Ordering {
+    let box_a = a
+        .bounding_box()
+        .expect("No bounding box in BvhNode constructor");
+    let box_b = b
+        .bounding_box()
+        .expect("No bounding box in BvhNode constructor");
+
+    box_a.min()[axis]
+        .partial_cmp(&box_b.min()[axis])
+        .unwrap_or(Ordering::Equal)
+}
+
+impl Hittable for BvhNode {
+    fn hit(&self, r: &Ray, t_min: f64, t_max: f64) -> Option<HitRecord> {
+        if !self.b_box().hit(r, t_min, t_max) {
+            return None;
+        }
+
+        match self {
+            BvhNode::Leaf { object, .. } => object.hit(r, t_min, t_max),
+            BvhNode::Branch { left, right, .. } => {
+                let hit_left = left.hit(r, t_min, t_max);
+                let closest = hit_left.as_ref().map_or(t_max, |rec| rec.t);
+                let hit_right = right.hit(r, t_min, closest);
+
+                hit_right.or(hit_left)
+            }
+        }
+    }
+
+    fn bounding_box(&self) -> Option<Aabb> {
+        Some(self.b_box())
+    }
+}
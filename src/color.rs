@@ -0,0 +1,25 @@
+use std::io::Write;
+
+use crate::common;
+use crate::vec3::Vec3;
+
+pub type Color = Vec3;
+
+/// Divides by the sample count and gamma-corrects (gamma = 2.0) into 8-bit channels.
+pub fn to_rgb8(pixel_color: Color, samples_per_pixel: i32) -> [u8; 3] {
+    let scale = 1.0 / samples_per_pixel as f64;
+    let r = (scale * pixel_color.x()).sqrt();
+    let g = (scale * pixel_color.y()).sqrt();
+    let b = (scale * pixel_color.z()).sqrt();
+
+    [
+        (256.0 * common::clamp(r, 0.0, 0.999)) as u8,
+        (256.0 * common::clamp(g, 0.0, 0.999)) as u8,
+        (256.0 * common::clamp(b, 0.0, 0.999)) as u8,
+    ]
+}
+
+pub fn write_color(out: &mut impl Write, pixel_color: Color, samples_per_pixel: i32) {
+    let [r, g, b] = to_rgb8(pixel_color, samples_per_pixel);
+    writeln!(out, "{} {} {}", r, g, b).expect("Error writing color");
+}
@@ -0,0 +1,92 @@
+use std::sync::Arc;
+
+use crate::aabb::Aabb;
+use crate::hittable::{HitRecord, Hittable};
+use crate::hittable_list::HittableList;
+use crate::material::Material;
+use crate::ray::Ray;
+use crate::rect::{Plane, Rect2D};
+use crate::vec3::Point3;
+
+pub struct Cuboid {
+    sides: HittableList,
+    b_box: Aabb,
+}
+
+impl Cuboid {
+    pub fn new(p0: Point3, p1: Point3, mat: Arc<dyn Material>) -> Cuboid {
+        let mut sides = HittableList::new();
+
+        sides.add(Box::new(Rect2D::new(
+            Plane::XY,
+            p0.x(),
+            p1.x(),
+            p0.y(),
+            p1.y(),
+            p1.z(),
+            mat.clone(),
+        )));
+        sides.add(Box::new(Rect2D::new(
+            Plane::XY,
+            p0.x(),
+            p1.x(),
+            p0.y(),
+            p1.y(),
+            p0.z(),
+            mat.clone(),
+        )));
+
+        sides.add(Box::new(Rect2D::new(
+            Plane::XZ,
+            p0.x(),
+            p1.x(),
+            p0.z(),
+            p1.z(),
+            p1.y(),
+            mat.clone(),
+        )));
+        sides.add(Box::new(Rect2D::new(
+            Plane::XZ,
+            p0.x(),
+            p1.x(),
+            p0.z(),
+            p1.z(),
+            p0.y(),
+            mat.clone(),
+        )));
+
+        sides.add(Box::new(Rect2D::new(
+            Plane::YZ,
+            p0.y(),
+            p1.y(),
+            p0.z(),
+            p1.z(),
+            p1.x(),
+            mat.clone(),
+        )));
+        sides.add(Box::new(Rect2D::new(
+            Plane::YZ,
+            p0.y(),
+            p1.y(),
+            p0.z(),
+            p1.z(),
+            p0.x(),
+            mat,
+        )));
+
+        Cuboid {
+            sides,
+            b_box: Aabb::new(p0, p1),
+        }
+    }
+}
+
+impl Hittable for Cuboid {
+    fn hit(&self, r: &Ray, t_min: f64, t_max: f64) -> Option<HitRecord> {
+        self.sides.hit(r, t_min, t_max)
+    }
+
+    fn bounding_box(&self) -> Option<Aabb> {
+        Some(self.b_box)
+    }
+}
@@ -0,0 +1,55 @@
+use crate::aabb::Aabb;
+use crate::bvh::BvhNode;
+use crate::hittable::{HitRecord, Hittable};
+use crate::ray::Ray;
+
+pub struct HittableList {
+    pub objects: Vec<Box<dyn Hittable>>,
+}
+
+impl HittableList {
+    pub fn new() -> HittableList {
+        HittableList {
+            objects: Vec::new(),
+        }
+    }
+
+    pub fn add(&mut self, object: Box<dyn Hittable>) {
+        self.objects.push(object);
+    }
+
+    /// Compiles this list into a bounding-volume hierarchy for faster traversal.
+    pub fn into_bvh(self) -> BvhNode {
+        BvhNode::new(self.objects)
+    }
+}
+
+impl Hittable for HittableList {
+    fn hit(&self, r: &Ray, t_min: f64, t_max: f64) -> Option<HitRecord> {
+        let mut closest_so_far = t_max;
+        let mut hit_record = None;
+
+        for object in self.objects.iter() {
+            if let Some(rec) = object.hit(r, t_min, closest_so_far) {
+                closest_so_far = rec.t;
+                hit_record = Some(rec);
+            }
+        }
+
+        hit_record
+    }
+
+    fn bounding_box(&self) -> Option<Aabb> {
+        let mut output_box: Option<Aabb> = None;
+
+        for object in self.objects.iter() {
+            let b_box = object.bounding_box()?;
+            output_box = Some(match output_box {
+                Some(acc) => Aabb::surrounding_box(acc, b_box),
+                None => b_box,
+            });
+        }
+
+        output_box
+    }
+}
@@ -0,0 +1,96 @@
+use std::sync::Arc;
+
+use crate::aabb::Aabb;
+use crate::hittable::{HitRecord, Hittable};
+use crate::material::Material;
+use crate::ray::Ray;
+use crate::vec3::{dot, Point3, Vec3};
+
+pub struct MovableSphere {
+    center0: Point3,
+    center1: Point3,
+    time0: f64,
+    time1: f64,
+    radius: f64,
+    mat: Arc<dyn Material>,
+}
+
+impl MovableSphere {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        center0: Point3,
+        center1: Point3,
+        time0: f64,
+        time1: f64,
+        radius: f64,
+        mat: Arc<dyn Material>,
+    ) -> MovableSphere {
+        MovableSphere {
+            center0,
+            center1,
+            time0,
+            time1,
+            radius,
+            mat,
+        }
+    }
+
+    pub fn center(&self, time: f64) -> Point3 {
+        self.center0
+            + ((time - self.time0) / (self.time1 - self.time0)) * (self.center1 - self.center0)
+    }
+}
+
+impl Hittable for MovableSphere {
+    fn hit(&self, r: &Ray, t_min: f64, t_max: f64) -> Option<HitRecord> {
+        let center = self.center(r.time());
+        let oc = r.origin() - center;
+        let a = r.direction().length_squared();
+        let half_b = dot(oc, r.direction());
+        let c = oc.length_squared() - self.radius * self.radius;
+
+        let discriminant = half_b * half_b - a * c;
+        if discriminant < 0.0 {
+            return None;
+        }
+        let sqrtd = discriminant.sqrt();
+
+        // Find the nearest root that lies in the acceptable range.
+        let mut root = (-half_b - sqrtd) / a;
+        if root < t_min || t_max < root {
+            root = (-half_b + sqrtd) / a;
+            if root < t_min || t_max < root {
+                return None;
+            }
+        }
+
+        let p = r.at(root);
+        let outward_normal = (p - center) / self.radius;
+        let mut rec = HitRecord {
+            t: root,
+            p,
+            normal: outward_normal,
+            mat: self.mat.clone(),
+            u: 0.0,
+            v: 0.0,
+            front_face: false,
+        };
+        rec.set_face_normal(r, outward_normal);
+
+        Some(rec)
+    }
+
+    fn bounding_box(&self) -> Option<Aabb> {
+        let radius = Vec3::new(self.radius, self.radius, self.radius);
+        let box0 = Aabb::new(
+            self.center(self.time0) - radius,
+            self.center(self.time0) + radius,
+        );
+        let box1 = Aabb::new(
+            self.center(self.time1) - radius,
+            self.center(self.time1) + radius,
+        );
+
+        Some(Aabb::surrounding_box(box0, box1))
+    }
+}
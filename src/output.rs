@@ -0,0 +1,51 @@
+use std::fs;
+use std::io::{BufWriter, Write};
+use std::path::Path;
+
+use crate::color::{self, Color};
+
+pub trait Output {
+    fn write(&self, pixels: &[Color], width: i32, height: i32, samples_per_pixel: i32, path: &str);
+}
+
+pub struct Ppm;
+
+impl Output for Ppm {
+    fn write(&self, pixels: &[Color], width: i32, height: i32, samples_per_pixel: i32, path: &str) {
+        let file =
+            fs::File::create(path).unwrap_or_else(|_| panic!("Failed to create file: {}", path));
+        let mut writer = BufWriter::new(file);
+
+        writeln!(&mut writer, "P3\n{} {}\n255\n", width, height).expect("Error writing header");
+
+        for pixel_color in pixels {
+            color::write_color(&mut writer, *pixel_color, samples_per_pixel);
+        }
+    }
+}
+
+pub struct Png;
+
+impl Output for Png {
+    fn write(&self, pixels: &[Color], width: i32, height: i32, samples_per_pixel: i32, path: &str) {
+        let mut img = image::RgbImage::new(width as u32, height as u32);
+
+        for (i, pixel_color) in pixels.iter().enumerate() {
+            let x = i as u32 % width as u32;
+            let y = i as u32 / width as u32;
+            let rgb = color::to_rgb8(*pixel_color, samples_per_pixel);
+            img.put_pixel(x, y, image::Rgb(rgb));
+        }
+
+        img.save(path)
+            .unwrap_or_else(|_| panic!("Failed to write PNG: {}", path));
+    }
+}
+
+/// Picks the output backend by the file extension of `path`, defaulting to PPM.
+pub fn for_path(path: &str) -> Box<dyn Output> {
+    match Path::new(path).extension().and_then(|ext| ext.to_str()) {
+        Some("png") => Box::new(Png),
+        _ => Box::new(Ppm),
+    }
+}
@@ -0,0 +1,108 @@
+use crate::common;
+use crate::vec3::{dot, unit_vector, Point3, Vec3};
+
+const POINT_COUNT: usize = 256;
+
+pub struct Perlin {
+    ranvec: Vec<Vec3>,
+    perm_x: Vec<i32>,
+    perm_y: Vec<i32>,
+    perm_z: Vec<i32>,
+}
+
+impl Perlin {
+    pub fn new() -> Perlin {
+        let ranvec = (0..POINT_COUNT)
+            .map(|_| unit_vector(Vec3::random_range(-1.0, 1.0)))
+            .collect();
+
+        Perlin {
+            ranvec,
+            perm_x: Perlin::generate_perm(),
+            perm_y: Perlin::generate_perm(),
+            perm_z: Perlin::generate_perm(),
+        }
+    }
+
+    pub fn noise(&self, p: &Point3) -> f64 {
+        let u = p.x() - p.x().floor();
+        let v = p.y() - p.y().floor();
+        let w = p.z() - p.z().floor();
+
+        let i = p.x().floor() as i32;
+        let j = p.y().floor() as i32;
+        let k = p.z().floor() as i32;
+
+        let mut c = [[[Vec3::new(0.0, 0.0, 0.0); 2]; 2]; 2];
+
+        for (di, row) in c.iter_mut().enumerate() {
+            for (dj, col) in row.iter_mut().enumerate() {
+                for (dk, cell) in col.iter_mut().enumerate() {
+                    let index = (self.perm_x[((i + di as i32) & 255) as usize]
+                        ^ self.perm_y[((j + dj as i32) & 255) as usize]
+                        ^ self.perm_z[((k + dk as i32) & 255) as usize])
+                        as usize;
+                    *cell = self.ranvec[index];
+                }
+            }
+        }
+
+        Perlin::trilinear_interp(c, u, v, w)
+    }
+
+    /// Sums turbulence across `depth` octaves for a marble-like banded pattern.
+    pub fn turb(&self, p: &Point3, depth: i32) -> f64 {
+        let mut accum = 0.0;
+        let mut temp_p = *p;
+        let mut weight = 1.0;
+
+        for _ in 0..depth {
+            accum += weight * self.noise(&temp_p);
+            weight *= 0.5;
+            temp_p *= 2.0;
+        }
+
+        accum.abs()
+    }
+
+    fn generate_perm() -> Vec<i32> {
+        let mut p: Vec<i32> = (0..POINT_COUNT as i32).collect();
+        Perlin::permute(&mut p);
+        p
+    }
+
+    fn permute(p: &mut [i32]) {
+        for i in (1..p.len()).rev() {
+            let target = common::random_int_range(0, i as i32) as usize;
+            p.swap(i, target);
+        }
+    }
+
+    fn trilinear_interp(c: [[[Vec3; 2]; 2]; 2], u: f64, v: f64, w: f64) -> f64 {
+        // Hermite-smooth the interpolation weights to avoid Mach-band artifacts.
+        let uu = u * u * (3.0 - 2.0 * u);
+        let vv = v * v * (3.0 - 2.0 * v);
+        let ww = w * w * (3.0 - 2.0 * w);
+        let mut accum = 0.0;
+
+        for (i, row) in c.iter().enumerate() {
+            for (j, col) in row.iter().enumerate() {
+                for (k, cell) in col.iter().enumerate() {
+                    let weight_v = Vec3::new(u - i as f64, v - j as f64, w - k as f64);
+                    accum += (i as f64 * uu + (1.0 - i as f64) * (1.0 - uu))
+                        * (j as f64 * vv + (1.0 - j as f64) * (1.0 - vv))
+                        * (k as f64 * ww + (1.0 - k as f64) * (1.0 - ww))
+                        * dot(*cell, weight_v);
+                }
+            }
+        }
+
+        accum
+    }
+}
+
+impl Default for Perlin {
+    fn default() -> Perlin {
+        Perlin::new()
+    }
+}
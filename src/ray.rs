@@ -0,0 +1,34 @@
+use crate::vec3::{Point3, Vec3};
+
+#[derive(Debug, Clone, Copy)]
+pub struct Ray {
+    orig: Point3,
+    dir: Vec3,
+    time: f64,
+}
+
+impl Ray {
+    pub fn new(origin: Point3, direction: Vec3, time: f64) -> Ray {
+        Ray {
+            orig: origin,
+            dir: direction,
+            time,
+        }
+    }
+
+    pub fn origin(&self) -> Point3 {
+        self.orig
+    }
+
+    pub fn direction(&self) -> Vec3 {
+        self.dir
+    }
+
+    pub fn time(&self) -> f64 {
+        self.time
+    }
+
+    pub fn at(&self, t: f64) -> Point3 {
+        self.orig + t * self.dir
+    }
+}
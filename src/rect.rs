@@ -0,0 +1,117 @@
+use std::sync::Arc;
+
+use crate::aabb::Aabb;
+use crate::hittable::{HitRecord, Hittable};
+use crate::material::Material;
+use crate::ray::Ray;
+use crate::vec3::{Point3, Vec3};
+
+#[derive(Clone, Copy)]
+pub enum Plane {
+    XY,
+    XZ,
+    YZ,
+}
+
+impl Plane {
+    /// Indices into a `Vec3`/`Point3` for (axis0, axis1, the fixed axis).
+    fn axes(self) -> (usize, usize, usize) {
+        match self {
+            Plane::XY => (0, 1, 2),
+            Plane::XZ => (0, 2, 1),
+            Plane::YZ => (1, 2, 0),
+        }
+    }
+}
+
+pub struct Rect2D {
+    plane: Plane,
+    a0_min: f64,
+    a0_max: f64,
+    a1_min: f64,
+    a1_max: f64,
+    k: f64,
+    mat: Arc<dyn Material>,
+}
+
+impl Rect2D {
+    pub fn new(
+        plane: Plane,
+        a0_min: f64,
+        a0_max: f64,
+        a1_min: f64,
+        a1_max: f64,
+        k: f64,
+        mat: Arc<dyn Material>,
+    ) -> Rect2D {
+        Rect2D {
+            plane,
+            a0_min,
+            a0_max,
+            a1_min,
+            a1_max,
+            k,
+            mat,
+        }
+    }
+}
+
+impl Hittable for Rect2D {
+    fn hit(&self, r: &Ray, t_min: f64, t_max: f64) -> Option<HitRecord> {
+        let (axis0, axis1, axis2) = self.plane.axes();
+
+        if r.direction()[axis2].abs() < 1e-8 {
+            return None;
+        }
+
+        let t = (self.k - r.origin()[axis2]) / r.direction()[axis2];
+        if t < t_min || t > t_max {
+            return None;
+        }
+
+        let a0 = r.origin()[axis0] + t * r.direction()[axis0];
+        let a1 = r.origin()[axis1] + t * r.direction()[axis1];
+        if a0 < self.a0_min || a0 > self.a0_max || a1 < self.a1_min || a1 > self.a1_max {
+            return None;
+        }
+
+        let mut outward_normal = [0.0, 0.0, 0.0];
+        outward_normal[axis2] = 1.0;
+        let outward_normal = Vec3::new(outward_normal[0], outward_normal[1], outward_normal[2]);
+
+        let p = r.at(t);
+        let mut rec = HitRecord {
+            t,
+            p,
+            normal: outward_normal,
+            mat: self.mat.clone(),
+            u: 0.0,
+            v: 0.0,
+            front_face: false,
+        };
+        rec.set_face_normal(r, outward_normal);
+
+        Some(rec)
+    }
+
+    fn bounding_box(&self) -> Option<Aabb> {
+        let (axis0, axis1, axis2) = self.plane.axes();
+
+        // Give the bounding box a small thickness along the fixed axis so the
+        // BVH slab test never produces a degenerate (zero-volume) box.
+        let mut min = [0.0, 0.0, 0.0];
+        let mut max = [0.0, 0.0, 0.0];
+
+        min[axis0] = self.a0_min;
+        max[axis0] = self.a0_max;
+        min[axis1] = self.a1_min;
+        max[axis1] = self.a1_max;
+        min[axis2] = self.k - 0.0001;
+        max[axis2] = self.k + 0.0001;
+
+        Some(Aabb::new(
+            Point3::new(min[0], min[1], min[2]),
+            Point3::new(max[0], max[1], max[2]),
+        ))
+    }
+}
@@ -0,0 +1,205 @@
+use std::sync::Arc;
+
+use crate::camera::Camera;
+use crate::color::Color;
+use crate::common;
+use crate::cuboid::Cuboid;
+use crate::hittable_list::HittableList;
+use crate::material::{Dielectric, DiffuseLight, Lambertian, Metal};
+use crate::movable_sphere::MovableSphere;
+use crate::rect::{Plane, Rect2D};
+use crate::sphere::Sphere;
+use crate::texture::{CheckerTexture, NoiseTexture, SolidColor};
+use crate::vec3::Point3;
+
+/// A scene bundled with the camera and background it's meant to be rendered with.
+pub struct Scene {
+    pub world: HittableList,
+    pub cam: Camera,
+    pub background: Color,
+}
+
+pub fn random_scene() -> HittableList {
+    let mut world = HittableList::new();
+
+    let checker = Arc::new(CheckerTexture::new(
+        Arc::new(SolidColor::new(Color::new(0.2, 0.3, 0.1))),
+        Arc::new(SolidColor::new(Color::new(0.9, 0.9, 0.9))),
+        10.0,
+    ));
+    let ground_material = Arc::new(Lambertian::with_texture(checker));
+    world.add(Box::new(Sphere::new(
+        Point3::new(0.0, -1000.0, 0.0),
+        1000.0,
+        ground_material,
+    )));
+
+    for a in -11..11 {
+        for b in -11..11 {
+            let choose_mat = common::random_double();
+            let center = Point3::new(
+                a as f64 + 0.9 * common::random_double(),
+                0.2,
+                b as f64 + 0.9 * common::random_double(),
+            );
+
+            if (center - Point3::new(4.0, 0.2, 0.0)).length() > 0.9 {
+                if choose_mat < 0.8 {
+                    // Diffuse
+                    let albedo = Color::random() * Color::random();
+                    let sphere_material = Arc::new(Lambertian::new(albedo));
+                    if choose_mat < 0.4 {
+                        // Give some diffuse spheres a small upward velocity for motion blur.
+                        let center1 =
+                            center + Point3::new(0.0, common::random_double_range(0.0, 0.5), 0.0);
+                        world.add(Box::new(MovableSphere::new(
+                            center,
+                            center1,
+                            0.0,
+                            1.0,
+                            0.2,
+                            sphere_material,
+                        )));
+                    } else {
+                        world.add(Box::new(Sphere::new(center, 0.2, sphere_material)));
+                    }
+                } else if choose_mat < 0.95 {
+                    // Metal
+                    let albedo = Color::random_range(0.5, 1.0);
+                    let fuzz = common::random_double_range(0.5, 1.0);
+                    let sphere_material = Arc::new(Metal::new(albedo, fuzz));
+                    world.add(Box::new(Sphere::new(center, 0.2, sphere_material)));
+                } else {
+                    // Glass
+                    let sphere_material = Arc::new(Dielectric::new(1.5));
+                    world.add(Box::new(Sphere::new(center, 0.2, sphere_material)));
+                }
+            }
+        }
+    }
+
+    let material1 = Arc::new(Dielectric::new(1.5));
+    world.add(Box::new(Sphere::new(
+        Point3::new(0.0, 1.0, 0.0),
+        1.0,
+        material1,
+    )));
+
+    let material2 = Arc::new(Lambertian::with_texture(Arc::new(NoiseTexture::new(4.0))));
+    world.add(Box::new(Sphere::new(
+        Point3::new(-4.0, 1.0, 0.0),
+        1.0,
+        material2,
+    )));
+
+    let material3 = Arc::new(Metal::new(Color::new(0.7, 0.6, 0.5), 0.0));
+    world.add(Box::new(Sphere::new(
+        Point3::new(4.0, 1.0, 0.0),
+        1.0,
+        material3,
+    )));
+
+    world
+}
+
+/// The classic Cornell box: a box of walls lit by a single ceiling light, used as a
+/// canonical test of global illumination.
+pub fn cornell_box() -> Scene {
+    let mut world = HittableList::new();
+
+    let red = Arc::new(Lambertian::new(Color::new(0.65, 0.05, 0.05)));
+    let white = Arc::new(Lambertian::new(Color::new(0.73, 0.73, 0.73)));
+    let green = Arc::new(Lambertian::new(Color::new(0.12, 0.45, 0.15)));
+    let light = Arc::new(DiffuseLight::new(Color::new(15.0, 15.0, 15.0)));
+
+    world.add(Box::new(Rect2D::new(
+        Plane::YZ,
+        0.0,
+        555.0,
+        0.0,
+        555.0,
+        555.0,
+        green,
+    )));
+    world.add(Box::new(Rect2D::new(
+        Plane::YZ,
+        0.0,
+        555.0,
+        0.0,
+        555.0,
+        0.0,
+        red,
+    )));
+    world.add(Box::new(Rect2D::new(
+        Plane::XZ,
+        213.0,
+        343.0,
+        227.0,
+        332.0,
+        554.0,
+        light,
+    )));
+    world.add(Box::new(Rect2D::new(
+        Plane::XZ,
+        0.0,
+        555.0,
+        0.0,
+        555.0,
+        0.0,
+        white.clone(),
+    )));
+    world.add(Box::new(Rect2D::new(
+        Plane::XZ,
+        0.0,
+        555.0,
+        0.0,
+        555.0,
+        555.0,
+        white.clone(),
+    )));
+    world.add(Box::new(Rect2D::new(
+        Plane::XY,
+        0.0,
+        555.0,
+        0.0,
+        555.0,
+        555.0,
+        white.clone(),
+    )));
+
+    world.add(Box::new(Cuboid::new(
+        Point3::new(130.0, 0.0, 65.0),
+        Point3::new(295.0, 165.0, 230.0),
+        white.clone(),
+    )));
+    world.add(Box::new(Cuboid::new(
+        Point3::new(265.0, 0.0, 295.0),
+        Point3::new(430.0, 330.0, 460.0),
+        white,
+    )));
+
+    let lookfrom = Point3::new(278.0, 278.0, -800.0);
+    let lookat = Point3::new(278.0, 278.0, 0.0);
+    let vup = Point3::new(0.0, 1.0, 0.0);
+    let aspect_ratio = 1.0;
+    let aperture = 0.0;
+    let dist_to_focus = 800.0;
+
+    let cam = Camera::new(
+        lookfrom,
+        lookat,
+        vup,
+        40.0,
+        aspect_ratio,
+        aperture,
+        dist_to_focus,
+        0.0,
+        1.0,
+    );
+
+    Scene {
+        world,
+        cam,
+        background: Color::new(0.0, 0.0, 0.0),
+    }
+}
@@ -0,0 +1,77 @@
+use std::sync::Arc;
+
+use crate::aabb::Aabb;
+use crate::common::PI;
+use crate::hittable::{HitRecord, Hittable};
+use crate::material::Material;
+use crate::ray::Ray;
+use crate::vec3::{dot, Point3, Vec3};
+
+/// Maps a point on the unit sphere to (u, v) texture coordinates from its spherical angles.
+fn get_sphere_uv(p: Point3) -> (f64, f64) {
+    let u = ((-p.z()).atan2(p.x()) + PI) / (2.0 * PI);
+    let v = (p.y().asin() + PI / 2.0) / PI;
+
+    (u, v)
+}
+
+pub struct Sphere {
+    center: Point3,
+    radius: f64,
+    mat: Arc<dyn Material>,
+}
+
+impl Sphere {
+    pub fn new(center: Point3, radius: f64, mat: Arc<dyn Material>) -> Sphere {
+        Sphere {
+            center,
+            radius,
+            mat,
+        }
+    }
+}
+
+impl Hittable for Sphere {
+    fn hit(&self, r: &Ray, t_min: f64, t_max: f64) -> Option<HitRecord> {
+        let oc = r.origin() - self.center;
+        let a = r.direction().length_squared();
+        let half_b = dot(oc, r.direction());
+        let c = oc.length_squared() - self.radius * self.radius;
+
+        let discriminant = half_b * half_b - a * c;
+        if discriminant < 0.0 {
+            return None;
+        }
+        let sqrtd = discriminant.sqrt();
+
+        // Find the nearest root that lies in the acceptable range.
+        let mut root = (-half_b - sqrtd) / a;
+        if root < t_min || t_max < root {
+            root = (-half_b + sqrtd) / a;
+            if root < t_min || t_max < root {
+                return None;
+            }
+        }
+
+        let p = r.at(root);
+        let outward_normal = (p - self.center) / self.radius;
+        let (u, v) = get_sphere_uv(outward_normal);
+        let mut rec = HitRecord {
+            t: root,
+            p,
+            normal: outward_normal,
+            mat: self.mat.clone(),
+            u,
+            v,
+            front_face: false,
+        };
+        rec.set_face_normal(r, outward_normal);
+
+        Some(rec)
+    }
+
+    fn bounding_box(&self) -> Option<Aabb> {
+        let radius = Vec3::new(self.radius, self.radius, self.radius);
+        Some(Aabb::new(self.center - radius, self.center + radius))
+    }
+}
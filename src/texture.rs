@@ -0,0 +1,114 @@
+use std::sync::Arc;
+
+use crate::color::Color;
+use crate::common;
+use crate::perlin::Perlin;
+use crate::vec3::Point3;
+
+pub trait Texture: Send + Sync {
+    fn value(&self, u: f64, v: f64, p: &Point3) -> Color;
+}
+
+pub struct SolidColor {
+    color_value: Color,
+}
+
+impl SolidColor {
+    pub fn new(color_value: Color) -> SolidColor {
+        SolidColor { color_value }
+    }
+}
+
+impl Texture for SolidColor {
+    fn value(&self, _u: f64, _v: f64, _p: &Point3) -> Color {
+        self.color_value
+    }
+}
+
+pub struct CheckerTexture {
+    even: Arc<dyn Texture>,
+    odd: Arc<dyn Texture>,
+    scale: f64,
+}
+
+impl CheckerTexture {
+    pub fn new(even: Arc<dyn Texture>, odd: Arc<dyn Texture>, scale: f64) -> CheckerTexture {
+        CheckerTexture { even, odd, scale }
+    }
+}
+
+impl Texture for CheckerTexture {
+    fn value(&self, u: f64, v: f64, p: &Point3) -> Color {
+        let sines =
+            (self.scale * p.x()).sin() * (self.scale * p.y()).sin() * (self.scale * p.z()).sin();
+        if sines < 0.0 {
+            self.odd.value(u, v, p)
+        } else {
+            self.even.value(u, v, p)
+        }
+    }
+}
+
+pub struct NoiseTexture {
+    noise: Perlin,
+    scale: f64,
+}
+
+impl NoiseTexture {
+    pub fn new(scale: f64) -> NoiseTexture {
+        NoiseTexture {
+            noise: Perlin::new(),
+            scale,
+        }
+    }
+}
+
+impl Texture for NoiseTexture {
+    fn value(&self, _u: f64, _v: f64, p: &Point3) -> Color {
+        Color::new(1.0, 1.0, 1.0)
+            * 0.5
+            * (1.0 + (self.scale * p.z() + 10.0 * self.noise.turb(p, 7)).sin())
+    }
+}
+
+// Not wired into any scene yet: doing so needs an actual image asset checked
+// into the repo, which this change doesn't add.
+#[allow(dead_code)]
+pub struct ImageTexture {
+    data: image::RgbImage,
+}
+
+#[allow(dead_code)]
+impl ImageTexture {
+    pub fn new(path: &str) -> ImageTexture {
+        let data = image::open(path)
+            .unwrap_or_else(|_| panic!("Failed to load texture image: {}", path))
+            .to_rgb8();
+        ImageTexture { data }
+    }
+}
+
+impl Texture for ImageTexture {
+    fn value(&self, u: f64, v: f64, _p: &Point3) -> Color {
+        let width = self.data.width();
+        let height = self.data.height();
+        if width == 0 || height == 0 {
+            // Debugging aid: cyan signals an empty (zero-sized) texture image.
+            return Color::new(0.0, 1.0, 1.0);
+        }
+
+        let u = common::clamp(u, 0.0, 1.0);
+        let v = 1.0 - common::clamp(v, 0.0, 1.0);
+
+        let i = ((u * width as f64) as u32).min(width - 1);
+        let j = ((v * height as f64) as u32).min(height - 1);
+
+        let pixel = self.data.get_pixel(i, j);
+        let color_scale = 1.0 / 255.0;
+        Color::new(
+            pixel[0] as f64 * color_scale,
+            pixel[1] as f64 * color_scale,
+            pixel[2] as f64 * color_scale,
+        )
+    }
+}